@@ -0,0 +1,61 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use fenris::element::ClosestPoint;
+use fenris::mesh::procedural::create_unit_square_uniform_tri_mesh_2d;
+use fenris::nalgebra::{Point2, Vector2, U2};
+use fenris::space::{ClosestPointInElementInSpace, FindClosestElement, SpatiallyIndexed};
+use std::hint::black_box;
+
+/// Finds the closest element by testing every element in the space.
+fn find_closest_element_linear_search<S>(space: &S, point: &Point2<f64>) -> Option<(usize, Point2<f64>)>
+where
+    S: ClosestPointInElementInSpace<f64, GeometryDim = U2, ReferenceDim = U2>,
+{
+    let mut closest: Option<(f64, usize, Point2<f64>)> = None;
+    for element_idx in 0..space.num_elements() {
+        match space.closest_point_in_element(element_idx, point) {
+            ClosestPoint::InElement(xi) => return Some((element_idx, xi)),
+            ClosestPoint::ClosestPoint(xi) => {
+                let dist2 = (space.map_element_reference_coords(element_idx, &xi) - point).norm_squared();
+                if closest.map(|(d2, _, _)| dist2 < d2).unwrap_or(true) {
+                    closest = Some((dist2, element_idx, xi));
+                }
+            }
+        }
+    }
+    closest.map(|(_, element_idx, xi)| (element_idx, xi))
+}
+
+/// Query points on a regular grid that slightly extends beyond the unit square.
+fn query_points(n: usize) -> Vec<Point2<f64>> {
+    let h = 1.2 / (n - 1) as f64;
+    (0..n)
+        .flat_map(|i| (0..n).map(move |j| Point2::new(-0.1, -0.1) + Vector2::new(i as f64, j as f64) * h))
+        .collect()
+}
+
+pub fn closest_element_tri3(c: &mut Criterion) {
+    let points = query_points(32);
+    for res in [8, 16, 32] {
+        let mesh = create_unit_square_uniform_tri_mesh_2d::<f64>(res);
+        c.bench_function(&format!("closest element linear search tri3 (res={res})"), |b| {
+            b.iter(|| {
+                for point in &points {
+                    black_box(find_closest_element_linear_search(&mesh, point));
+                }
+            })
+        });
+
+        let indexed = SpatiallyIndexed::from_space(mesh);
+        c.bench_function(&format!("closest element spatially indexed tri3 (res={res})"), |b| {
+            b.iter(|| {
+                for point in &points {
+                    black_box(indexed.find_closest_element_and_reference_coords(point));
+                }
+            })
+        });
+    }
+}
+
+criterion_group!(spatial, closest_element_tri3);
+
+criterion_main!(spatial);